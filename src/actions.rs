@@ -1,4 +1,5 @@
 use crate::bindings::Binding;
+use serde::Deserialize;
 use std::fmt;
 use std::fmt::Debug;
 use winit::keyboard::ModifiersState;
@@ -6,12 +7,24 @@ use winit::keyboard::ModifiersState;
 pub const KEY_BINDINGS: &[Binding<&'static str>] = &[
     Binding::new("Q", ModifiersState::CONTROL, Action::CloseWindow),
     Binding::new("F", ModifiersState::CONTROL, Action::ToggleFullscreen),
+    Binding::new("]", ModifiersState::empty(), Action::NextVideoMode),
+    Binding::new("[", ModifiersState::empty(), Action::PrevVideoMode),
+    Binding::new("N", ModifiersState::CONTROL, Action::NewWindow),
+    Binding::new(
+        "N",
+        ModifiersState::CONTROL.union(ModifiersState::SHIFT),
+        Action::NewWindowOnNextMonitor,
+    ),
 ];
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum Action {
     CloseWindow,
     ToggleFullscreen,
+    NextVideoMode,
+    PrevVideoMode,
+    NewWindow,
+    NewWindowOnNextMonitor,
 }
 
 impl Action {
@@ -19,6 +32,10 @@ impl Action {
         match self {
             Action::CloseWindow => "Close window",
             Action::ToggleFullscreen => "Toggle fullscreen",
+            Action::NextVideoMode => "Select next exclusive video mode",
+            Action::PrevVideoMode => "Select previous exclusive video mode",
+            Action::NewWindow => "Open a new VRR test window",
+            Action::NewWindowOnNextMonitor => "Open a new VRR test window on the next monitor",
         }
     }
 }
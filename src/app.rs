@@ -1,21 +1,30 @@
-use crate::actions::{Action, KEY_BINDINGS};
+use crate::actions::Action;
+use crate::bindings::Binding;
+use crate::config;
 use crate::utils::{load_icon, modifiers_to_string};
 use crate::window_state::WindowState;
 use softbuffer::Context;
 use std::collections::HashMap;
 use std::error::Error;
 use std::mem;
-use tracing::info;
+use tracing::{error, info};
 use winit::dpi::{PhysicalPosition, PhysicalSize};
 use winit::event_loop::{ActiveEventLoop, EventLoop};
 use winit::keyboard::ModifiersState;
 use winit::raw_window_handle::{DisplayHandle, HasDisplayHandle};
 use winit::window::{Icon, Window, WindowId};
 
+/// Default Wayland `app_id` / X11 `WM_CLASS` applied to every window, so a
+/// compositor rule (e.g. forcing tearing or adaptive sync) can target this
+/// tool specifically. Overridable via the config file or `VRR_TEST_APP_ID`.
+pub(crate) const DEFAULT_APP_ID: &str = "vrr-test";
+
 pub struct Application {
     icon: Icon,
     pub(crate) windows: HashMap<WindowId, WindowState>,
     pub(crate) context: Option<Context<DisplayHandle<'static>>>,
+    bindings: Vec<Binding<String>>,
+    app_id: String,
 }
 
 impl Application {
@@ -36,11 +45,14 @@ impl Application {
         // going too high, or you'll be bitten by the low-quality downscaling built into the
         // WM.
         let icon = load_icon(include_bytes!("data/icon.png"));
+        let config = config::Config::load();
 
         Self {
             context,
             icon,
             windows: Default::default(),
+            bindings: config.bindings,
+            app_id: config.app_id,
         }
     }
 
@@ -48,13 +60,34 @@ impl Application {
         &mut self,
         event_loop: &ActiveEventLoop,
         _tab_id: Option<String>,
+        position: Option<PhysicalPosition<i32>>,
     ) -> Result<WindowId, Box<dyn Error>> {
-        #[allow(unused_mut)]
         let mut window_attributes = Window::default_attributes()
             .with_title("VRR Test")
             .with_transparent(true)
             .with_window_icon(Some(self.icon.clone()));
 
+        #[cfg(target_os = "linux")]
+        {
+            use winit::platform::wayland::WindowAttributesExtWayland;
+            use winit::platform::x11::WindowAttributesExtX11;
+
+            window_attributes = WindowAttributesExtX11::with_name(
+                window_attributes,
+                self.app_id.clone(),
+                self.app_id.clone(),
+            );
+            window_attributes = WindowAttributesExtWayland::with_name(
+                window_attributes,
+                self.app_id.clone(),
+                self.app_id.clone(),
+            );
+        }
+
+        if let Some(position) = position {
+            window_attributes = window_attributes.with_position(position);
+        }
+
         let window = event_loop.create_window(window_attributes)?;
 
         let window_state = WindowState::new(self, window)?;
@@ -64,14 +97,67 @@ impl Application {
         Ok(window_id)
     }
 
-    pub(crate) fn handle_action(&mut self, window_id: WindowId, action: Action) {
-        let window = self.windows.get_mut(&window_id).unwrap();
+    pub(crate) fn handle_action(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: WindowId,
+        action: Action,
+    ) {
         info!("Executing action: {action:?}");
         match action {
             Action::CloseWindow => {
                 let _ = self.windows.remove(&window_id);
             }
-            Action::ToggleFullscreen => window.toggle_fullscreen(),
+            Action::ToggleFullscreen => {
+                self.windows.get_mut(&window_id).unwrap().toggle_fullscreen()
+            }
+            Action::NextVideoMode => self
+                .windows
+                .get_mut(&window_id)
+                .unwrap()
+                .cycle_video_mode(true),
+            Action::PrevVideoMode => self
+                .windows
+                .get_mut(&window_id)
+                .unwrap()
+                .cycle_video_mode(false),
+            Action::NewWindow => {
+                if let Err(err) = self.create_window(event_loop, None, None) {
+                    error!("Failed to create new window: {err}");
+                }
+            }
+            Action::NewWindowOnNextMonitor => {
+                self.create_window_on_next_monitor(event_loop, window_id)
+            }
+        }
+    }
+
+    fn create_window_on_next_monitor(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        active_window_id: WindowId,
+    ) {
+        let monitors: Vec<_> = event_loop.available_monitors().collect();
+        if monitors.is_empty() {
+            error!("No monitors available to place a new window on");
+            return;
+        }
+
+        let current_monitor = self
+            .windows
+            .get(&active_window_id)
+            .and_then(|window| window.window.current_monitor());
+
+        let next_monitor = match current_monitor {
+            Some(current) => {
+                let index = monitors.iter().position(|m| *m == current).unwrap_or(0);
+                monitors[(index + 1) % monitors.len()].clone()
+            }
+            None => monitors[0].clone(),
+        };
+
+        if let Err(err) = self.create_window(event_loop, None, Some(next_monitor.position())) {
+            error!("Failed to create window on next monitor: {err}");
         }
     }
 
@@ -120,17 +206,15 @@ impl Application {
         }
     }
 
-    pub(crate) fn process_key_binding(key: &str, mods: &ModifiersState) -> Option<Action> {
-        KEY_BINDINGS.iter().find_map(|binding| {
-            binding
-                .is_triggered_by(&key, mods)
-                .then_some(binding.action)
-        })
+    pub(crate) fn process_key_binding(&self, key: &str, mods: &ModifiersState) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find_map(|binding| binding.is_triggered_by(key, mods).then_some(binding.action))
     }
 
     pub(crate) fn print_help(&self) {
         info!("Keyboard bindings:");
-        for binding in KEY_BINDINGS {
+        for binding in &self.bindings {
             info!(
                 "{}{:<10} - {} ({})",
                 modifiers_to_string(binding.mods),
@@ -1,4 +1,7 @@
 use crate::actions::Action;
+use crate::utils::parse_modifiers;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
 use winit::keyboard::ModifiersState;
 
 pub struct Binding<T: Eq> {
@@ -15,8 +18,30 @@ impl<T: Eq> Binding<T> {
             action,
         }
     }
+}
+
+impl<T: Eq + AsRef<str>> Binding<T> {
+    pub(crate) fn is_triggered_by(&self, trigger: &str, mods: &ModifiersState) -> bool {
+        self.trigger.as_ref() == trigger && &self.mods == mods
+    }
+}
+
+/// A binding as it appears in a config file, e.g. `{ trigger = "F", mods = "Ctrl", action = "ToggleFullscreen" }`.
+#[derive(Deserialize)]
+struct RawBinding {
+    trigger: String,
+    #[serde(default)]
+    mods: String,
+    action: Action,
+}
 
-    pub(crate) fn is_triggered_by(&self, trigger: &T, mods: &ModifiersState) -> bool {
-        &self.trigger == trigger && &self.mods == mods
+impl<'de> Deserialize<'de> for Binding<String> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawBinding::deserialize(deserializer)?;
+        let mods = parse_modifiers(&raw.mods).map_err(D::Error::custom)?;
+        Ok(Binding::new(raw.trigger, mods, raw.action))
     }
 }
@@ -0,0 +1,107 @@
+use crate::actions::KEY_BINDINGS;
+use crate::app::DEFAULT_APP_ID;
+use crate::bindings::Binding;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, error};
+
+/// Overrides the config file location; falls back to a file named
+/// [`CONFIG_FILE_NAME`] next to the binary.
+const CONFIG_ENV_VAR: &str = "VRR_TEST_CONFIG";
+const CONFIG_FILE_NAME: &str = "vrr-test.toml";
+
+/// Overrides the Wayland `app_id` / X11 `WM_CLASS` set on created windows.
+const APP_ID_ENV_VAR: &str = "VRR_TEST_APP_ID";
+
+pub struct Config {
+    pub bindings: Vec<Binding<String>>,
+    pub app_id: String,
+}
+
+impl Config {
+    /// Loads keybindings and the window app id from a TOML or JSON config
+    /// file, falling back to [`KEY_BINDINGS`] / [`DEFAULT_APP_ID`] when the
+    /// file is missing or fails to parse. The `VRR_TEST_APP_ID` env var
+    /// always wins over the config file, so a compositor rule can be pinned
+    /// without editing it.
+    pub fn load() -> Self {
+        let app_id_override = env::var(APP_ID_ENV_VAR).ok();
+        let raw = config_path().and_then(|path| read_raw_config(&path));
+
+        let (bindings, app_id) = match raw {
+            Some(raw) => (raw.bindings, raw.app_id),
+            None => (default_bindings(), None),
+        };
+
+        Self {
+            bindings,
+            app_id: app_id_override
+                .or(app_id)
+                .unwrap_or_else(|| DEFAULT_APP_ID.to_string()),
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var(CONFIG_ENV_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    let exe_dir = env::current_exe().ok()?.parent()?.to_path_buf();
+    let candidate = exe_dir.join(CONFIG_FILE_NAME);
+    candidate.exists().then_some(candidate)
+}
+
+fn read_raw_config(path: &Path) -> Option<RawConfig> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            error!(
+                "Failed to read config {}: {err}, using built-in defaults",
+                path.display()
+            );
+            return None;
+        }
+    };
+
+    let result = if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+        serde_json::from_str::<RawConfig>(&contents).map_err(|err| err.to_string())
+    } else {
+        toml::from_str::<RawConfig>(&contents).map_err(|err| err.to_string())
+    };
+
+    match result {
+        Ok(raw) => {
+            debug!(
+                "Loaded {} keybinding(s) from {}",
+                raw.bindings.len(),
+                path.display()
+            );
+            Some(raw)
+        }
+        Err(err) => {
+            error!(
+                "Failed to parse config {}: {err}, using built-in defaults",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+fn default_bindings() -> Vec<Binding<String>> {
+    KEY_BINDINGS
+        .iter()
+        .map(|binding| Binding::new(binding.trigger.to_string(), binding.mods, binding.action))
+        .collect()
+}
+
+/// On-disk shape of the config file.
+#[derive(serde::Deserialize)]
+struct RawConfig {
+    #[serde(default)]
+    bindings: Vec<Binding<String>>,
+    #[serde(default)]
+    app_id: Option<String>,
+}
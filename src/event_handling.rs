@@ -11,7 +11,7 @@ impl ApplicationHandler<UserEvent> for Application {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         self.dump_monitors(event_loop);
 
-        self.create_window(event_loop, None)
+        self.create_window(event_loop, None, None)
             .expect("failed to create initial window");
 
         self.print_help();
@@ -21,7 +21,7 @@ impl ApplicationHandler<UserEvent> for Application {
 
     fn window_event(
         &mut self,
-        _event_loop: &ActiveEventLoop,
+        event_loop: &ActiveEventLoop,
         window_id: WindowId,
         event: WindowEvent,
     ) {
@@ -43,6 +43,16 @@ impl ApplicationHandler<UserEvent> for Application {
             WindowEvent::Occluded(occluded) => {
                 window.set_occluded(occluded);
             }
+            WindowEvent::ScaleFactorChanged {
+                scale_factor,
+                inner_size_writer,
+                ..
+            } => {
+                let new_size = window.rescale(scale_factor);
+                if let Err(err) = inner_size_writer.request_inner_size(new_size) {
+                    error!("Failed to request inner size on scale factor change: {err}");
+                }
+            }
             WindowEvent::CloseRequested => {
                 self.windows.remove(&window_id);
             }
@@ -59,13 +69,13 @@ impl ApplicationHandler<UserEvent> for Application {
 
                 if event.state.is_pressed() {
                     let action = if let Key::Character(ch) = event.logical_key.as_ref() {
-                        Self::process_key_binding(&ch.to_uppercase(), &mods)
+                        self.process_key_binding(&ch.to_uppercase(), &mods)
                     } else {
                         None
                     };
 
                     if let Some(action) = action {
-                        self.handle_action(window_id, action);
+                        self.handle_action(event_loop, window_id, action);
                     }
                 }
             }
@@ -9,6 +9,7 @@ use crate::tracing::init_tracing;
 mod actions;
 mod app;
 mod bindings;
+mod config;
 mod event_handling;
 mod tracing;
 mod utils;
@@ -14,7 +14,12 @@ pub fn load_icon(bytes: &[u8]) -> Icon {
 pub fn modifiers_to_string(mods: ModifiersState) -> String {
     let mut mods_line = String::new();
     // Always add + since it's printed as a part of the bindings.
-    for (modifier, desc) in [(ModifiersState::CONTROL, "Ctrl+")] {
+    for (modifier, desc) in [
+        (ModifiersState::CONTROL, "Ctrl+"),
+        (ModifiersState::SHIFT, "Shift+"),
+        (ModifiersState::ALT, "Alt+"),
+        (ModifiersState::SUPER, "Super+"),
+    ] {
         if !mods.contains(modifier) {
             continue;
         }
@@ -23,3 +28,20 @@ pub fn modifiers_to_string(mods: ModifiersState) -> String {
     }
     mods_line
 }
+
+/// Parses a modifier spec like `"Ctrl+Shift"` (case-insensitive, `+`-separated)
+/// into a [`ModifiersState`]. An empty string means no modifiers.
+pub fn parse_modifiers(spec: &str) -> Result<ModifiersState, String> {
+    let mut mods = ModifiersState::empty();
+    for part in spec.split('+') {
+        match part.trim().to_ascii_lowercase().as_str() {
+            "" => {}
+            "ctrl" | "control" => mods |= ModifiersState::CONTROL,
+            "shift" => mods |= ModifiersState::SHIFT,
+            "alt" => mods |= ModifiersState::ALT,
+            "super" | "meta" | "cmd" | "win" => mods |= ModifiersState::SUPER,
+            other => return Err(format!("unknown modifier {other:?}")),
+        }
+    }
+    Ok(mods)
+}
@@ -4,11 +4,20 @@ use softbuffer::Surface;
 use std::error::Error;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::info;
 use winit::dpi::{PhysicalPosition, PhysicalSize};
 use winit::keyboard::ModifiersState;
+use winit::monitor::VideoMode;
 use winit::raw_window_handle::DisplayHandle;
 use winit::window::{Fullscreen, Window};
+
+/// How fast the sweeping bar moves, in pixels per frame.
+const BAR_SPEED: u32 = 4;
+
+/// Number of frames between rolling frame-time reports.
+const STATS_WINDOW: u64 = 120;
+
 pub struct WindowState {
     surface: Surface<DisplayHandle<'static>, Arc<Window>>,
     pub(crate) window: Arc<Window>,
@@ -16,6 +25,18 @@ pub struct WindowState {
     cursor_position: Option<PhysicalPosition<f64>>,
     pub(crate) modifiers: ModifiersState,
     occluded: bool,
+    size: PhysicalSize<u32>,
+    /// The scale factor as of the last `ScaleFactorChanged` event, kept so
+    /// `rescale` can convert the current logical size to physical pixels
+    /// when the next change arrives.
+    pub(crate) scale_factor: f64,
+    selected_mode_index: usize,
+
+    frame: u64,
+    last_present: Option<Instant>,
+    frame_time_sum: Duration,
+    frame_time_min: Duration,
+    frame_time_max: Duration,
 }
 
 impl WindowState {
@@ -28,14 +49,29 @@ impl WindowState {
         window.set_cursor(CursorIcon::Default);
 
         let size = window.inner_size();
+        let scale_factor = window.scale_factor();
         let mut state = Self {
             surface,
             window,
             cursor_position: Default::default(),
             modifiers: Default::default(),
             occluded: Default::default(),
+            size,
+            scale_factor,
+            selected_mode_index: 0,
+            frame: 0,
+            last_present: None,
+            frame_time_sum: Duration::ZERO,
+            frame_time_min: Duration::MAX,
+            frame_time_max: Duration::ZERO,
         };
 
+        // `sorted_video_modes` is ascending, so the best (highest
+        // resolution/refresh-rate) mode is the last entry: default to it so
+        // pressing Ctrl+F without ever cycling modes hits the native/highest
+        // mode rather than the worst one.
+        state.selected_mode_index = state.sorted_video_modes().len().saturating_sub(1);
+
         state.resize(size);
         Ok(state)
     }
@@ -49,66 +85,111 @@ impl WindowState {
     }
 
     pub(crate) fn toggle_fullscreen(&self) {
-        #[cfg(target_os = "linux")]
-        let fullscreen_option = Some(Fullscreen::Borderless(None));
-
-        #[cfg(target_os = "windows")]
-        let fullscreen_option = {
-            let current_monitor = self
-                .window
-                .current_monitor()
-                .expect("Failed to get current monitor");
-            let current_video_mode = current_monitor
-                .video_modes()
-                .max_by_key(|mode| {
-                    (
-                        mode.size().width,
-                        mode.size().height,
-                        mode.refresh_rate_millihertz(),
-                    )
-                })
-                .expect("Failed to get max video mode");
-
-            Some(Fullscreen::Exclusive(current_video_mode))
-        };
-
         let fullscreen = if self.window.fullscreen().is_some() {
             info!("Exiting fullscreen");
             None
         } else {
-            #[cfg(target_os = "windows")]
-            if let Some(Fullscreen::Exclusive(video_mode)) = &fullscreen_option {
-                let mode = video_mode.size();
-                let refresh_rate = video_mode.refresh_rate_millihertz() / 1000; // Convert millihertz to hertz
-                info!(
-                    "Entering fullscreen: {}x{}@{}Hz",
-                    mode.width, mode.height, refresh_rate
-                );
-            }
-            #[cfg(target_os = "linux")]
-            {
-                info!("Entering fullscreen: Borderless");
-            }
-            fullscreen_option
+            let fullscreen = match self.sorted_video_modes().get(self.selected_mode_index) {
+                Some(mode) => {
+                    let size = mode.size();
+                    let refresh_rate = mode.refresh_rate_millihertz() / 1000;
+                    info!(
+                        "Entering fullscreen: {}x{}@{}Hz",
+                        size.width, size.height, refresh_rate
+                    );
+                    Fullscreen::Exclusive(mode.clone())
+                }
+                None => {
+                    info!("Entering fullscreen: Borderless (no exclusive video modes available)");
+                    Fullscreen::Borderless(None)
+                }
+            };
+            Some(fullscreen)
         };
 
         self.window.set_fullscreen(fullscreen);
     }
 
-    pub(crate) fn resize(&mut self, size: PhysicalSize<u32>) {
-        {
-            let (width, height) = match (NonZeroU32::new(size.width), NonZeroU32::new(size.height))
-            {
-                (Some(width), Some(height)) => (width, height),
-                _ => return,
-            };
-            self.surface
-                .resize(width, height)
-                .expect("failed to resize inner buffer");
+    /// Collects the current monitor's exclusive video modes, sorted by
+    /// resolution then refresh rate, so `selected_mode_index` is stable
+    /// across calls.
+    fn sorted_video_modes(&self) -> Vec<VideoMode> {
+        let mut modes: Vec<_> = self
+            .window
+            .current_monitor()
+            .map(|monitor| monitor.video_modes().collect())
+            .unwrap_or_default();
+        modes.sort_by_key(|mode| {
+            (
+                mode.size().width,
+                mode.size().height,
+                mode.refresh_rate_millihertz(),
+            )
+        });
+        modes
+    }
+
+    pub(crate) fn cycle_video_mode(&mut self, forward: bool) {
+        let modes = self.sorted_video_modes();
+        if modes.is_empty() {
+            info!("No exclusive video modes available on the current monitor");
+            return;
         }
+
+        let len = modes.len();
+        self.selected_mode_index = if forward {
+            (self.selected_mode_index + 1) % len
+        } else {
+            (self.selected_mode_index + len - 1) % len
+        };
+
+        let mode = &modes[self.selected_mode_index];
+        let size = mode.size();
+        info!(
+            "Selected video mode: {}x{}x{} @ {}.{} Hz",
+            size.width,
+            size.height,
+            mode.bit_depth(),
+            mode.refresh_rate_millihertz() / 1000,
+            mode.refresh_rate_millihertz() % 1000,
+        );
+    }
+
+    pub(crate) fn resize(&mut self, size: PhysicalSize<u32>) {
+        let (width, height) = match (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) {
+            (Some(width), Some(height)) => (width, height),
+            // A zero-sized resize (e.g. minimizing) is a no-op for the surface,
+            // so leave `self.size` at its last valid value rather than letting
+            // `draw` derive its row stride from a size that was never applied.
+            _ => return,
+        };
+        self.size = size;
+        self.surface
+            .resize(width, height)
+            .expect("failed to resize inner buffer");
         self.window.request_redraw();
     }
 
+    /// Recomputes the physical inner size for a new scale factor, keeping the
+    /// window's logical size constant, and resizes the softbuffer surface to
+    /// match.
+    pub(crate) fn rescale(&mut self, scale_factor: f64) -> PhysicalSize<u32> {
+        let old_scale_factor = self.scale_factor;
+        let new_size = PhysicalSize::new(
+            (self.size.width as f64 / old_scale_factor * scale_factor).round() as u32,
+            (self.size.height as f64 / old_scale_factor * scale_factor).round() as u32,
+        );
+
+        info!(
+            "Scale factor changed for window={:?}: {old_scale_factor} -> {scale_factor}",
+            self.window.id()
+        );
+
+        self.scale_factor = scale_factor;
+        self.resize(new_size);
+        new_size
+    }
+
     pub(crate) fn set_occluded(&mut self, occluded: bool) {
         self.occluded = occluded;
         if !occluded {
@@ -122,9 +203,54 @@ impl WindowState {
             return Ok(());
         }
 
-        let buffer = self.surface.buffer_mut()?;
+        let width = self.size.width.max(1) as usize;
+
+        let mut buffer = self.surface.buffer_mut()?;
+
+        // Full-screen flash between two luminances so dropped/held frames are
+        // immediately visible as a break in the alternating pattern.
+        let flash = if self.frame % 2 == 0 {
+            0x00202020
+        } else {
+            0x00e0e0e0
+        };
+
+        // A bar that sweeps left to right so tearing shows up as a visible seam.
+        let bar_x = (self.frame.wrapping_mul(BAR_SPEED as u64) % width as u64) as usize;
+
+        for (i, pixel) in buffer.iter_mut().enumerate() {
+            let x = i % width;
+            *pixel = if x == bar_x { 0x00ff3b30 } else { flash };
+        }
+
         self.window.pre_present_notify();
         buffer.present()?;
+
+        let now = Instant::now();
+        if let Some(last_present) = self.last_present {
+            let dt = now - last_present;
+            self.frame_time_sum += dt;
+            self.frame_time_min = self.frame_time_min.min(dt);
+            self.frame_time_max = self.frame_time_max.max(dt);
+
+            if self.frame % STATS_WINDOW == 0 {
+                let avg = self.frame_time_sum / STATS_WINDOW as u32;
+                info!(
+                    "frame timing (last {STATS_WINDOW}): avg={:.2}ms min={:.2}ms max={:.2}ms (~{:.1} Hz)",
+                    avg.as_secs_f64() * 1000.0,
+                    self.frame_time_min.as_secs_f64() * 1000.0,
+                    self.frame_time_max.as_secs_f64() * 1000.0,
+                    1.0 / avg.as_secs_f64(),
+                );
+                self.frame_time_sum = Duration::ZERO;
+                self.frame_time_min = Duration::MAX;
+                self.frame_time_max = Duration::ZERO;
+            }
+        }
+        self.last_present = Some(now);
+        self.frame += 1;
+
+        self.window.request_redraw();
         Ok(())
     }
 }